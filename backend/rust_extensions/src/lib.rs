@@ -1,33 +1,67 @@
 use pyo3::prelude::*;
+use rayon::prelude::*;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use std::ops::Deref;
 use std::path::PathBuf;
 
-/// Find JSON record boundaries in AntiSMASH output files.
-/// 
-/// This function scans a JSON file to find the byte positions of individual
-/// records within the "records" array. It's optimized for speed and handles
-/// large files (2GB+) efficiently.
-/// 
-/// Args:
-///     file_path: Path to the JSON file to scan
-/// 
-/// Returns:
-///     List of tuples (start_byte, end_byte) for each record
-#[pyfunction]
-fn scan_records(file_path: PathBuf) -> PyResult<Vec<(u64, u64)>> {
-    let file = File::open(&file_path)
+/// A read-only byte view over a scan input.
+///
+/// Backed by an `mmap`ed file so resident memory stays bounded to the OS page
+/// cache, or by a fully buffered read for inputs that cannot be mapped (pipes,
+/// compressed streams, empty files).
+enum ScanInput {
+    Mapped(memmap2::Mmap),
+    Buffered(Vec<u8>),
+}
+
+impl Deref for ScanInput {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            ScanInput::Mapped(mmap) => mmap,
+            ScanInput::Buffered(content) => content,
+        }
+    }
+}
+
+/// Load a file as a byte view, preferring an `mmap` when `use_mmap` is set.
+///
+/// Falls back to a buffered read when the file cannot be memory-mapped (for
+/// example a zero-length file or a non-regular input), keeping behaviour
+/// identical to the original scanners for those cases.
+fn load_input(file_path: &PathBuf, use_mmap: bool) -> PyResult<ScanInput> {
+    let file = File::open(file_path)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
             format!("Failed to open file: {}", e)
         ))?;
-    
+
+    if use_mmap {
+        // SAFETY: the file is opened read-only and the mapping lives no longer
+        // than the owning `ScanInput`; callers only ever read from the view.
+        match unsafe { memmap2::Mmap::map(&file) } {
+            Ok(mmap) => return Ok(ScanInput::Mapped(mmap)),
+            // Pipes, zero-length files and other non-mappable inputs fall back
+            // to a buffered read below rather than failing the scan.
+            Err(_) => {}
+        }
+    }
+
     let mut reader = BufReader::with_capacity(8 * 1024 * 1024, file); // 8MB buffer
     let mut content = Vec::new();
     reader.read_to_end(&mut content)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
             format!("Failed to read file: {}", e)
         ))?;
-    
+    Ok(ScanInput::Buffered(content))
+}
+
+/// Locate record boundaries within an already-loaded byte view.
+///
+/// Shared by the file- and mmap-backed entry points so both see identical
+/// brace-depth scanning semantics.
+fn find_record_ranges(content: &[u8]) -> PyResult<Vec<(u64, u64)>> {
     // Find the "records" array
     let records_pattern = b"\"records\"";
     let records_pos = content.windows(records_pattern.len())
@@ -35,7 +69,7 @@ fn scan_records(file_path: PathBuf) -> PyResult<Vec<(u64, u64)>> {
         .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
             "Could not find 'records' array in JSON file"
         ))?;
-    
+
     // Find the opening bracket of the records array
     let array_start = content[records_pos..]
         .iter()
@@ -43,9 +77,9 @@ fn scan_records(file_path: PathBuf) -> PyResult<Vec<(u64, u64)>> {
         .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
             "Could not find opening bracket for 'records' array"
         ))?;
-    
+
     let start_pos = records_pos + array_start + 1;
-    
+
     // Scan for record boundaries
     let mut positions = Vec::new();
     let mut pos = start_pos;
@@ -53,33 +87,33 @@ fn scan_records(file_path: PathBuf) -> PyResult<Vec<(u64, u64)>> {
     let mut record_start: Option<usize> = None;
     let mut in_string = false;
     let mut escape_next = false;
-    
+
     while pos < content.len() {
         let byte = content[pos];
-        
+
         if escape_next {
             escape_next = false;
             pos += 1;
             continue;
         }
-        
+
         if byte == b'\\' {
             escape_next = true;
             pos += 1;
             continue;
         }
-        
+
         if byte == b'"' {
             in_string = !in_string;
             pos += 1;
             continue;
         }
-        
+
         if in_string {
             pos += 1;
             continue;
         }
-        
+
         match byte {
             b'{' => {
                 if brace_depth == 0 {
@@ -101,126 +135,345 @@ fn scan_records(file_path: PathBuf) -> PyResult<Vec<(u64, u64)>> {
             }
             _ => {}
         }
-        
+
         pos += 1;
     }
-    
+
     Ok(positions)
 }
 
-/// Scan for both records and features in an AntiSMASH JSON file.
-/// 
-/// This function finds byte positions for both records and their nested features
-/// in a single pass. It's optimized for files where you need feature-level access
-/// without loading entire records.
-/// 
+/// Find JSON record boundaries in AntiSMASH output files.
+///
+/// This function scans a JSON file to find the byte positions of individual
+/// records within the "records" array. It's optimized for speed and handles
+/// large files (2GB+) efficiently.
+///
+/// By default the file is memory-mapped so resident memory stays bounded to
+/// the OS page cache rather than allocating the whole file on the heap. Pass
+/// `use_mmap=False` to force the buffered-read path for non-mmappable inputs.
+///
 /// Args:
 ///     file_path: Path to the JSON file to scan
-/// 
+///     use_mmap: Memory-map the file instead of reading it into RAM (default True)
+///
 /// Returns:
-///     List of tuples (record_start, record_end, features) where features is a
-///     list of (feature_start, feature_end) tuples. All positions are absolute
-///     byte offsets in the file.
+///     List of tuples (start_byte, end_byte) for each record
 #[pyfunction]
-fn scan_records_and_features(file_path: PathBuf) -> PyResult<Vec<(u64, u64, Vec<(u64, u64)>)>> {
-    let file = File::open(&file_path)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
-            format!("Failed to open file: {}", e)
-        ))?;
-    
-    let mut reader = BufReader::with_capacity(8 * 1024 * 1024, file);
-    let mut content = Vec::new();
-    reader.read_to_end(&mut content)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
-            format!("Failed to read file: {}", e)
-        ))?;
-    
-    // Find the "records" array
-    let records_pattern = b"\"records\"";
-    let records_pos = content.windows(records_pattern.len())
-        .position(|window| window == records_pattern)
-        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "Could not find 'records' array in JSON file"
-        ))?;
-    
-    let array_start = content[records_pos..]
-        .iter()
-        .position(|&b| b == b'[')
-        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "Could not find opening bracket for 'records' array"
-        ))?;
-    
-    let start_pos = records_pos + array_start + 1;
-    
-    let mut results = Vec::new();
-    let mut pos = start_pos;
-    let mut brace_depth = 0;
-    let mut record_start: Option<usize> = None;
+#[pyo3(signature = (file_path, use_mmap=true))]
+fn scan_records(file_path: PathBuf, use_mmap: bool) -> PyResult<Vec<(u64, u64)>> {
+    let content = load_input(&file_path, use_mmap)?;
+    find_record_ranges(&content)
+}
+
+/// Find record boundaries in a newline-delimited JSON (NDJSON) file.
+///
+/// Large AntiSMASH/BGC dumps are increasingly stored with one complete record
+/// per line rather than wrapped in a single `"records": [...]` array. Instead
+/// of tracking brace depth, this locates record boundaries at newline
+/// positions that fall outside any JSON string, giving O(n) detection with no
+/// brace bookkeeping. Blank lines and trailing whitespace are skipped.
+///
+/// Args:
+///     file_path: Path to the NDJSON file to scan
+///     use_mmap: Memory-map the file instead of reading it into RAM (default True)
+///
+/// Returns:
+///     List of tuples (line_start, line_end) for each non-empty record line
+#[pyfunction]
+#[pyo3(signature = (file_path, use_mmap=true))]
+fn scan_ndjson_records(file_path: PathBuf, use_mmap: bool) -> PyResult<Vec<(u64, u64)>> {
+    let content = load_input(&file_path, use_mmap)?;
+    Ok(find_ndjson_ranges(&content))
+}
+
+/// Locate NDJSON record boundaries within a loaded byte view.
+fn find_ndjson_ranges(content: &[u8]) -> Vec<(u64, u64)> {
+    let mut positions = Vec::new();
+    let mut line_start = 0usize;
     let mut in_string = false;
     let mut escape_next = false;
-    
-    // Scan for records
+
+    let mut pos = 0usize;
     while pos < content.len() {
         let byte = content[pos];
-        
+
         if escape_next {
             escape_next = false;
             pos += 1;
             continue;
         }
-        
+
         if byte == b'\\' {
             escape_next = true;
             pos += 1;
             continue;
         }
-        
+
         if byte == b'"' {
             in_string = !in_string;
             pos += 1;
             continue;
         }
-        
-        if in_string {
-            pos += 1;
-            continue;
+
+        if !in_string && byte == b'\n' {
+            push_ndjson_line(content, line_start, pos, &mut positions);
+            line_start = pos + 1;
         }
-        
-        match byte {
-            b'{' => {
-                if brace_depth == 0 {
-                    record_start = Some(pos);
-                }
-                brace_depth += 1;
+
+        pos += 1;
+    }
+
+    // Emit any trailing record not terminated by a final newline.
+    push_ndjson_line(content, line_start, content.len(), &mut positions);
+
+    positions
+}
+
+/// Trim surrounding whitespace from a candidate line and record it if non-empty.
+fn push_ndjson_line(content: &[u8], start: usize, end: usize, positions: &mut Vec<(u64, u64)>) {
+    let mut s = start;
+    let mut e = end;
+    while s < e && content[s].is_ascii_whitespace() {
+        s += 1;
+    }
+    while e > s && content[e - 1].is_ascii_whitespace() {
+        e -= 1;
+    }
+    if s < e {
+        positions.push((s as u64, e as u64));
+    }
+}
+
+/// Chunk size for ranged reads against remote object stores.
+const REMOTE_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Incremental depth-0 record boundary scanner for streamed input.
+///
+/// Mirrors the brace-depth state machine used for local scans but carries its
+/// state across chunk boundaries, so a file can be fed one ranged GET at a
+/// time without ever being fully resident. Absolute offsets are preserved so
+/// downstream ranged reads of individual records work against the same store.
+struct RecordBoundaryScanner {
+    brace_depth: i32,
+    record_start: Option<u64>,
+    in_string: bool,
+    escape_next: bool,
+    done: bool,
+    positions: Vec<(u64, u64)>,
+}
+
+impl RecordBoundaryScanner {
+    fn new() -> Self {
+        RecordBoundaryScanner {
+            brace_depth: 0,
+            record_start: None,
+            in_string: false,
+            escape_next: false,
+            done: false,
+            positions: Vec::new(),
+        }
+    }
+
+    /// Feed a chunk whose first byte sits at absolute offset `base`.
+    fn feed(&mut self, chunk: &[u8], base: u64) {
+        let mut i = 0;
+        while i < chunk.len() {
+            if self.done {
+                return;
             }
-            b'}' => {
-                brace_depth -= 1;
-                if brace_depth == 0 {
-                    if let Some(rec_start) = record_start {
-                        let rec_end = pos + 1;
-                        
-                        // Now scan for features within this record
-                        let features = scan_features_in_range(&content, rec_start, rec_end);
-                        
-                        results.push((rec_start as u64, rec_end as u64, features));
-                        record_start = None;
+
+            let pos = base + i as u64;
+            let byte = chunk[i];
+
+            if self.escape_next {
+                self.escape_next = false;
+                i += 1;
+                continue;
+            }
+
+            if byte == b'\\' {
+                self.escape_next = true;
+                i += 1;
+                continue;
+            }
+
+            if byte == b'"' {
+                self.in_string = !self.in_string;
+                i += 1;
+                continue;
+            }
+
+            if self.in_string {
+                i += 1;
+                continue;
+            }
+
+            match byte {
+                b'{' => {
+                    if self.brace_depth == 0 {
+                        self.record_start = Some(pos);
+                    }
+                    self.brace_depth += 1;
+                }
+                b'}' => {
+                    self.brace_depth -= 1;
+                    if self.brace_depth == 0 {
+                        if let Some(start) = self.record_start.take() {
+                            self.positions.push((start, pos + 1));
+                        }
                     }
                 }
+                b']' if self.brace_depth == 0 => {
+                    self.done = true;
+                    return;
+                }
+                _ => {}
             }
-            b']' if brace_depth == 0 => {
-                break;
+
+            i += 1;
+        }
+    }
+}
+
+/// Scan record boundaries in an AntiSMASH JSON object stored remotely.
+///
+/// Accepts any URI understood by the `object_store` crate (`s3://`, `gs://`,
+/// `az://`, `https://`, …), where AntiSMASH output now commonly lives. The
+/// object is read via ranged GET requests: a prefix is pulled first to locate
+/// the `"records"` array, then the body is streamed through the same
+/// brace-depth state machine used for local files, so the whole object never
+/// needs to be resident. Returned offsets are byte-absolute within the object.
+///
+/// Args:
+///     uri: Object-store URI of the JSON object to scan
+///
+/// Returns:
+///     List of tuples (start_byte, end_byte) for each record
+#[pyfunction]
+fn scan_records_url(uri: &str) -> PyResult<Vec<(u64, u64)>> {
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("Failed to create async runtime: {}", e)
+        ))?;
+    runtime.block_on(scan_records_url_async(uri))
+}
+
+async fn scan_records_url_async(uri: &str) -> PyResult<Vec<(u64, u64)>> {
+    let url = url::Url::parse(uri)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Invalid object-store URI: {}", e)
+        ))?;
+    let (store, path) = object_store::parse_url(&url)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Unsupported object-store URI: {}", e)
+        ))?;
+
+    let total = store.head(&path).await
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("Failed to stat object: {}", e)
+        ))?
+        .size as u64;
+
+    // Phase 1: pull a growing prefix until the "records" array's opening
+    // bracket is found, so we know the absolute offset to start streaming from.
+    let records_pattern = b"\"records\"";
+    let mut prefix: Vec<u8> = Vec::new();
+    let mut start_pos: Option<u64> = None;
+    while start_pos.is_none() && (prefix.len() as u64) < total {
+        let begin = prefix.len();
+        let end = (begin + REMOTE_CHUNK_SIZE).min(total as usize);
+        let bytes = store.get_range(&path, begin..end).await
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+                format!("Failed to read object range: {}", e)
+            ))?;
+        prefix.extend_from_slice(&bytes);
+
+        if let Some(records_pos) = prefix.windows(records_pattern.len())
+            .position(|window| window == records_pattern) {
+            if let Some(array_start) = prefix[records_pos..]
+                .iter()
+                .position(|&b| b == b'[') {
+                start_pos = Some((records_pos + array_start + 1) as u64);
             }
-            _ => {}
         }
-        
-        pos += 1;
     }
-    
+
+    let start_pos = start_pos.ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "Could not find 'records' array in object"
+    ))?;
+
+    // Phase 2: stream the array body through the incremental scanner. Bytes
+    // already pulled into the prefix are reused before fetching further ranges.
+    let mut scanner = RecordBoundaryScanner::new();
+    if (start_pos as usize) < prefix.len() {
+        scanner.feed(&prefix[start_pos as usize..], start_pos);
+    }
+    let mut offset = prefix.len().max(start_pos as usize) as u64;
+    while !scanner.done && offset < total {
+        let begin = offset as usize;
+        let end = (begin + REMOTE_CHUNK_SIZE).min(total as usize);
+        let bytes = store.get_range(&path, begin..end).await
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+                format!("Failed to read object range: {}", e)
+            ))?;
+        scanner.feed(&bytes, offset);
+        offset = end as u64;
+    }
+
+    Ok(scanner.positions)
+}
+
+/// Scan for both records and features in an AntiSMASH JSON file.
+/// 
+/// This function finds byte positions for both records and their nested features:
+/// one pass over the file for the records, then a feature scan of each record's
+/// range, run in parallel across records. It's optimized for files where you need feature-level access
+/// without loading entire records.
+/// 
+/// Args:
+///     file_path: Path to the JSON file to scan
+/// 
+/// Returns:
+///     List of tuples (record_start, record_end, features) where features is a
+///     list of (feature_start, feature_end, feature_type) tuples and
+///     feature_type is the feature's "type" string when present. All positions
+///     are absolute byte offsets in the file.
+///
+/// Like `scan_records`, the file is memory-mapped by default; pass
+/// `use_mmap=False` to force the buffered-read path.
+#[pyfunction]
+#[pyo3(signature = (file_path, use_mmap=true))]
+fn scan_records_and_features(file_path: PathBuf, use_mmap: bool) -> PyResult<Vec<(u64, u64, Vec<(u64, u64, Option<String>)>)>> {
+    let content = load_input(&file_path, use_mmap)?;
+    find_records_and_features(&content)
+}
+
+/// Locate record and nested-feature boundaries within a loaded byte view.
+///
+/// Runs in two phases: a cheap single-threaded depth-0 pass collects every
+/// record's byte range, then feature extraction fans out across those ranges
+/// with rayon. Each record's range is disjoint and self-contained, so no
+/// cross-record brace state is shared and the work is embarrassingly parallel.
+/// Input order is preserved in the returned vector.
+fn find_records_and_features(content: &[u8]) -> PyResult<Vec<(u64, u64, Vec<(u64, u64, Option<String>)>)>> {
+    let ranges = find_record_ranges(content)?;
+
+    let results = ranges
+        .par_iter()
+        .map(|&(rec_start, rec_end)| {
+            let features = scan_features_in_range(content, rec_start as usize, rec_end as usize);
+            (rec_start, rec_end, features)
+        })
+        .collect();
+
     Ok(results)
 }
 
 /// Helper function to scan for features within a record's byte range.
-fn scan_features_in_range(content: &[u8], start: usize, end: usize) -> Vec<(u64, u64)> {
+///
+/// Each feature additionally carries its `"type"` string (when present) so
+/// viewers can filter by feature class without loading the feature body.
+fn scan_features_in_range(content: &[u8], start: usize, end: usize) -> Vec<(u64, u64, Option<String>)> {
     // Find the "features" array within the record
     let features_pattern = b"\"features\"";
     
@@ -286,7 +539,8 @@ fn scan_features_in_range(content: &[u8], start: usize, end: usize) -> Vec<(u64,
                         // Convert to absolute file positions
                         let abs_start = start + feat_start;
                         let abs_end = start + pos + 1;
-                        positions.push((abs_start as u64, abs_end as u64));
+                        let feature_type = extract_key_value(&record_slice[feat_start..pos + 1], "type");
+                        positions.push((abs_start as u64, abs_end as u64, feature_type));
                         feature_start = None;
                     }
                 }
@@ -303,10 +557,315 @@ fn scan_features_in_range(content: &[u8], start: usize, end: usize) -> Vec<(u64,
     positions
 }
 
+/// Read a JSON string literal starting just after its opening quote.
+///
+/// Handles backslash escapes the way the scanners do: the backslash is dropped
+/// and the byte after it is kept as is, so `\"`, `\\` and `\/` come out right.
+/// Other escapes are not translated (`\n` yields `n`, `\uXXXX` yields `uXXXX`).
+/// Returns the contents up to the closing quote.
+fn read_json_string(buf: &[u8], start: usize) -> String {
+    let mut out = Vec::new();
+    let mut pos = start;
+    let mut escape_next = false;
+
+    while pos < buf.len() {
+        let byte = buf[pos];
+
+        if escape_next {
+            out.push(byte);
+            escape_next = false;
+            pos += 1;
+            continue;
+        }
+
+        if byte == b'\\' {
+            escape_next = true;
+            pos += 1;
+            continue;
+        }
+
+        if byte == b'"' {
+            break;
+        }
+
+        out.push(byte);
+        pos += 1;
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Capture the string value of a top-level `key` within an object's byte range.
+///
+/// Walks the object tracking brace depth; when a string key at object depth 1
+/// matches `key` and is followed by `:` and a string literal, the literal's
+/// contents are returned. Returns `None` if the key is absent or its value is
+/// not a string.
+fn extract_key_value(object: &[u8], key: &str) -> Option<String> {
+    let key_bytes = key.as_bytes();
+    let mut pos = 0;
+    let mut brace_depth = 0i32;
+    let mut in_string = false;
+    let mut escape_next = false;
+    let mut string_start = 0usize;
+
+    while pos < object.len() {
+        let byte = object[pos];
+
+        if in_string {
+            if escape_next {
+                escape_next = false;
+                pos += 1;
+                continue;
+            }
+            if byte == b'\\' {
+                escape_next = true;
+                pos += 1;
+                continue;
+            }
+            if byte == b'"' {
+                in_string = false;
+                // A string at object depth 1 followed by ':' is a key.
+                if brace_depth == 1 {
+                    let mut j = pos + 1;
+                    while j < object.len() && object[j].is_ascii_whitespace() {
+                        j += 1;
+                    }
+                    if j < object.len() && object[j] == b':' && &object[string_start..pos] == key_bytes {
+                        let mut k = j + 1;
+                        while k < object.len() && object[k].is_ascii_whitespace() {
+                            k += 1;
+                        }
+                        if k < object.len() && object[k] == b'"' {
+                            return Some(read_json_string(object, k + 1));
+                        }
+                        return None;
+                    }
+                }
+                pos += 1;
+                continue;
+            }
+            pos += 1;
+            continue;
+        }
+
+        match byte {
+            b'"' => {
+                in_string = true;
+                string_start = pos + 1;
+            }
+            b'{' => brace_depth += 1,
+            b'}' => brace_depth -= 1,
+            _ => {}
+        }
+
+        pos += 1;
+    }
+
+    None
+}
+
+/// Scan record boundaries, capturing a chosen top-level key's value per record.
+///
+/// Walks each depth-0 record object as `scan_records` does, but additionally
+/// captures the string value of the given top-level `key` (e.g. `"id"` or
+/// `"name"`), so callers can resolve "find the record named X" without
+/// re-parsing every record body.
+///
+/// Args:
+///     file_path: Path to the JSON file to scan
+///     key: Top-level key whose string value to capture per record
+///     use_mmap: Memory-map the file instead of reading it into RAM (default True)
+///
+/// Returns:
+///     List of tuples (start_byte, end_byte, value) where value is the captured
+///     key's string, or None if the record has no such string-valued key.
+#[pyfunction]
+#[pyo3(signature = (file_path, key, use_mmap=true))]
+fn scan_records_with_key(file_path: PathBuf, key: &str, use_mmap: bool) -> PyResult<Vec<(u64, u64, Option<String>)>> {
+    let content = load_input(&file_path, use_mmap)?;
+    let ranges = find_record_ranges(&content)?;
+    Ok(ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let value = extract_key_value(&content[start as usize..end as usize], key);
+            (start, end, value)
+        })
+        .collect())
+}
+
+/// Magic + version header identifying a bgc-scanner sidecar index.
+const INDEX_MAGIC: &[u8; 8] = b"BGCIDX01";
+
+/// Read the length and modification time (seconds since the epoch) of a file.
+///
+/// These are stored in the index header and compared on load so a stale index
+/// (source rewritten since it was built) is detected and regenerated.
+fn source_len_mtime(file_path: &PathBuf) -> PyResult<(u64, u64)> {
+    let meta = std::fs::metadata(file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("Failed to stat file: {}", e)
+        ))?;
+    let mtime = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok((meta.len(), mtime))
+}
+
+/// Serialize scan results into the compact on-disk index layout.
+///
+/// Layout (all integers little-endian `u64`): magic, source length, source
+/// mtime, record count, then per record `(start, end, feature_count)` followed
+/// by each feature's `(start, end)` and its type as a length-prefixed UTF-8
+/// string (length `u64::MAX` marks an absent type).
+fn encode_index(source_len: u64, source_mtime: u64, records: &[(u64, u64, Vec<(u64, u64, Option<String>)>)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(INDEX_MAGIC);
+    buf.extend_from_slice(&source_len.to_le_bytes());
+    buf.extend_from_slice(&source_mtime.to_le_bytes());
+    buf.extend_from_slice(&(records.len() as u64).to_le_bytes());
+    for (start, end, features) in records {
+        buf.extend_from_slice(&start.to_le_bytes());
+        buf.extend_from_slice(&end.to_le_bytes());
+        buf.extend_from_slice(&(features.len() as u64).to_le_bytes());
+        for (fstart, fend, ftype) in features {
+            buf.extend_from_slice(&fstart.to_le_bytes());
+            buf.extend_from_slice(&fend.to_le_bytes());
+            match ftype {
+                Some(t) => {
+                    buf.extend_from_slice(&(t.len() as u64).to_le_bytes());
+                    buf.extend_from_slice(t.as_bytes());
+                }
+                None => buf.extend_from_slice(&u64::MAX.to_le_bytes()),
+            }
+        }
+    }
+    buf
+}
+
+/// Read a little-endian `u64` at `pos`, advancing it past the eight bytes.
+fn read_u64(buf: &[u8], pos: &mut usize) -> PyResult<u64> {
+    let end = *pos + 8;
+    let slice = buf.get(*pos..end).ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "Index file is truncated"
+    ))?;
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(slice);
+    *pos = end;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Parse an index buffer, returning the stored source metadata and records.
+fn decode_index(buf: &[u8]) -> PyResult<(u64, u64, Vec<(u64, u64, Vec<(u64, u64, Option<String>)>)>)> {
+    if buf.len() < INDEX_MAGIC.len() || &buf[..INDEX_MAGIC.len()] != INDEX_MAGIC {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "Not a bgc-scanner index file"
+        ));
+    }
+    let mut pos = INDEX_MAGIC.len();
+    let source_len = read_u64(buf, &mut pos)?;
+    let source_mtime = read_u64(buf, &mut pos)?;
+    let record_count = read_u64(buf, &mut pos)?;
+
+    let mut records = Vec::with_capacity(record_count as usize);
+    for _ in 0..record_count {
+        let start = read_u64(buf, &mut pos)?;
+        let end = read_u64(buf, &mut pos)?;
+        let feature_count = read_u64(buf, &mut pos)?;
+        let mut features = Vec::with_capacity(feature_count as usize);
+        for _ in 0..feature_count {
+            let fstart = read_u64(buf, &mut pos)?;
+            let fend = read_u64(buf, &mut pos)?;
+            let type_len = read_u64(buf, &mut pos)?;
+            let ftype = if type_len == u64::MAX {
+                None
+            } else {
+                let end = pos + type_len as usize;
+                let slice = buf.get(pos..end).ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Index file is truncated"
+                ))?;
+                let value = String::from_utf8_lossy(slice).into_owned();
+                pos = end;
+                Some(value)
+            };
+            features.push((fstart, fend, ftype));
+        }
+        records.push((start, end, features));
+    }
+    Ok((source_len, source_mtime, records))
+}
+
+/// Build a reusable sidecar index for a JSON file.
+///
+/// Scans the file once with `scan_records_and_features` and writes the
+/// resulting record/feature offsets to `index_path` in a compact binary form,
+/// tagged with the source file's length and mtime so staleness can be detected
+/// later. Subsequent opens can `load_index` instead of re-scanning.
+///
+/// Args:
+///     file_path: Path to the JSON file to index
+///     index_path: Path to write the sidecar index to
+#[pyfunction]
+fn build_index(file_path: PathBuf, index_path: PathBuf) -> PyResult<()> {
+    let (source_len, source_mtime) = source_len_mtime(&file_path)?;
+    let content = load_input(&file_path, true)?;
+    let records = find_records_and_features(&content)?;
+    let buf = encode_index(source_len, source_mtime, &records);
+    std::fs::write(&index_path, &buf)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("Failed to write index: {}", e)
+        ))?;
+    Ok(())
+}
+
+/// Load a sidecar index, regenerating it if stale.
+///
+/// The index header records the source file's length and mtime; if either has
+/// changed since the index was built the index is rebuilt from `file_path`
+/// before returning. A missing index is likewise built on demand, so callers
+/// can treat this as a cheap-if-possible open.
+///
+/// Args:
+///     file_path: Path to the source JSON file
+///     index_path: Path to the sidecar index
+///
+/// Returns:
+///     List of tuples (record_start, record_end, features) as produced by
+///     `scan_records_and_features`.
+#[pyfunction]
+fn load_index(file_path: PathBuf, index_path: PathBuf) -> PyResult<Vec<(u64, u64, Vec<(u64, u64, Option<String>)>)>> {
+    let (source_len, source_mtime) = source_len_mtime(&file_path)?;
+
+    if let Ok(buf) = std::fs::read(&index_path) {
+        if let Ok((stored_len, stored_mtime, records)) = decode_index(&buf) {
+            if stored_len == source_len && stored_mtime == source_mtime {
+                return Ok(records);
+            }
+        }
+    }
+
+    // Missing, unreadable, corrupt or stale: rebuild from the source.
+    build_index(file_path, index_path.clone())?;
+    let buf = std::fs::read(&index_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(
+            format!("Failed to read index: {}", e)
+        ))?;
+    let (_, _, records) = decode_index(&buf)?;
+    Ok(records)
+}
+
 /// A Python module implemented in Rust for fast JSON record scanning.
 #[pymodule]
 fn bgc_scanner(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(scan_records, m)?)?;
+    m.add_function(wrap_pyfunction!(scan_records_url, m)?)?;
+    m.add_function(wrap_pyfunction!(scan_ndjson_records, m)?)?;
+    m.add_function(wrap_pyfunction!(scan_records_with_key, m)?)?;
     m.add_function(wrap_pyfunction!(scan_records_and_features, m)?)?;
+    m.add_function(wrap_pyfunction!(build_index, m)?)?;
+    m.add_function(wrap_pyfunction!(load_index, m)?)?;
     Ok(())
 }